@@ -0,0 +1,245 @@
+//! Integration with the [`axum`](https://docs.rs/axum) web framework.
+
+#[cfg(any(feature = "json", feature = "xml"))]
+use axum::http::{header, StatusCode};
+#[cfg(any(feature = "json", feature = "xml"))]
+use axum::response::{IntoResponse, Response};
+
+#[cfg(feature = "json")]
+use axum::extract::{FromRequest, Request};
+
+#[cfg(all(feature = "json", feature = "xml"))]
+use axum::extract::FromRequestParts;
+#[cfg(all(feature = "json", feature = "xml"))]
+use axum::http::request::Parts;
+#[cfg(all(feature = "json", feature = "xml"))]
+use axum::http::{HeaderMap, HeaderValue};
+
+#[cfg(any(feature = "json", feature = "xml"))]
+use crate::ProblemDetails;
+
+#[cfg(feature = "json")]
+const PROBLEM_JSON: &str = "application/problem+json";
+#[cfg(feature = "xml")]
+const PROBLEM_XML: &str = "application/problem+xml";
+
+#[cfg(all(feature = "json", not(feature = "xml")))]
+impl<Ext: serde::Serialize> IntoResponse for ProblemDetails<Ext> {
+    fn into_response(self) -> Response {
+        let status = self
+            .status
+            .unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+        let body = serde_json::to_vec(&self).unwrap_or_default();
+        (status, [(header::CONTENT_TYPE, PROBLEM_JSON)], body).into_response()
+    }
+}
+
+#[cfg(all(feature = "xml", not(feature = "json")))]
+impl<Ext: serde::Serialize> IntoResponse for ProblemDetails<Ext> {
+    fn into_response(self) -> Response {
+        let status = self
+            .status
+            .unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+        let body = quick_xml::se::to_string(&self).unwrap_or_default();
+        (status, [(header::CONTENT_TYPE, PROBLEM_XML)], body).into_response()
+    }
+}
+
+/// Deserializes an upstream `application/problem+json` response body into a
+/// typed [`ProblemDetails<Ext>`], so services acting as clients/proxies can
+/// consume problem details as easily as they produce them.
+///
+/// Mirrors axum's own [`Json`](axum::Json) extractor in distinguishing
+/// syntactically invalid JSON (`400 Bad Request`) from well-formed JSON that
+/// doesn't match `Ext` (`422 Unprocessable Entity`), rejecting with a
+/// [`ProblemDetails`] describing the parse failure in both cases.
+// axum-core 0.4's `FromRequest`/`FromRequestParts` are declared with
+// `#[async_trait]` (boxed-future desugaring), not native async-fn-in-traits,
+// so implementations need the matching attribute too. Requires the
+// `async-trait` crate as a dependency of the `axum` feature.
+#[cfg(feature = "json")]
+#[async_trait::async_trait]
+impl<S, Ext> FromRequest<S> for ProblemDetails<Ext>
+where
+    S: Sync + Send,
+    Ext: serde::de::DeserializeOwned,
+{
+    type Rejection = ProblemRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = axum::body::Bytes::from_request(req, state)
+            .await
+            .map_err(|err| {
+                ProblemRejection(
+                    ProblemDetails::from_status_code(StatusCode::BAD_REQUEST)
+                        .with_title("Failed to buffer the request body")
+                        .with_detail(err.to_string()),
+                )
+            })?;
+
+        let value: serde_json::Value = serde_json::from_slice(&bytes).map_err(|err| {
+            ProblemRejection(
+                ProblemDetails::from_status_code(StatusCode::BAD_REQUEST)
+                    .with_title("Request body is not syntactically valid JSON")
+                    .with_detail(err.to_string()),
+            )
+        })?;
+
+        serde_json::from_value(value).map_err(|err| {
+            ProblemRejection(
+                ProblemDetails::from_status_code(StatusCode::UNPROCESSABLE_ENTITY)
+                    .with_title("Request body is not a valid problem details object")
+                    .with_detail(err.to_string()),
+            )
+        })
+    }
+}
+
+/// The rejection returned by the [`ProblemDetails`] extractor.
+///
+/// `ProblemDetails<()>` itself only implements [`IntoResponse`] when exactly
+/// one of the `json`/`xml` features is enabled (see the fixed-format impls
+/// above) — with both enabled, only [`ProblemResponse`] does, since
+/// negotiating a format needs the request's `Accept` header, which a
+/// `Rejection` doesn't have access to. `ProblemRejection` always serializes
+/// as `application/problem+json` instead, regardless of which response
+/// format features are enabled, so the extractor's `Rejection: IntoResponse`
+/// bound is satisfied in every `json` configuration.
+#[cfg(feature = "json")]
+pub struct ProblemRejection(ProblemDetails);
+
+#[cfg(feature = "json")]
+impl IntoResponse for ProblemRejection {
+    fn into_response(self) -> Response {
+        let status = self.0.status.unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = serde_json::to_vec(&self.0).unwrap_or_default();
+        (status, [(header::CONTENT_TYPE, PROBLEM_JSON)], body).into_response()
+    }
+}
+
+/// The representation negotiated from an incoming `Accept` header, see
+/// [`ProblemResponse`].
+#[cfg(all(feature = "json", feature = "xml"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemFormat {
+    /// Serialize as `application/problem+json`.
+    Json,
+    /// Serialize as `application/problem+xml`.
+    Xml,
+}
+
+/// Returns whether `accept` accepts `media_type`, i.e. it lists it with no
+/// `q` parameter or a `q` parameter greater than zero. A `q=0` entry is an
+/// explicit refusal of that media type and must not be treated as accepted.
+#[cfg(all(feature = "json", feature = "xml"))]
+fn accepts(accept: &str, media_type: &str) -> bool {
+    accept.split(',').any(|entry| {
+        let mut params = entry.split(';').map(str::trim);
+        if params.next() != Some(media_type) {
+            return false;
+        }
+        let q = params
+            .find_map(|param| param.strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        q > 0.0
+    })
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+impl ProblemFormat {
+    /// Negotiates a format from an `Accept` header, preferring XML only when
+    /// it is requested (with a non-zero `q`) and JSON is not, and falling
+    /// back to JSON otherwise.
+    ///
+    /// ```
+    /// use axum::http::{header, HeaderMap, HeaderValue};
+    /// use problem_details::axum::ProblemFormat;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert(header::ACCEPT, HeaderValue::from_static("application/problem+xml"));
+    /// assert_eq!(ProblemFormat::negotiate(&headers), ProblemFormat::Xml);
+    ///
+    /// // A `q=0` entry is an explicit refusal, not a preference.
+    /// headers.insert(header::ACCEPT, HeaderValue::from_static("application/problem+xml;q=0"));
+    /// assert_eq!(ProblemFormat::negotiate(&headers), ProblemFormat::Json);
+    /// ```
+    pub fn negotiate(headers: &HeaderMap) -> Self {
+        let accept = headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        if accepts(accept, PROBLEM_XML) && !accepts(accept, PROBLEM_JSON) {
+            Self::Xml
+        } else {
+            Self::Json
+        }
+    }
+
+    fn content_type(self) -> HeaderValue {
+        HeaderValue::from_static(match self {
+            Self::Json => PROBLEM_JSON,
+            Self::Xml => PROBLEM_XML,
+        })
+    }
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+#[async_trait::async_trait]
+impl<S: Sync> FromRequestParts<S> for ProblemFormat {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::negotiate(&parts.headers))
+    }
+}
+
+/// Wraps a [`ProblemDetails`] together with a negotiated [`ProblemFormat`],
+/// so a single handler can satisfy clients requesting either
+/// `application/problem+json` or `application/problem+xml`, as intended by
+/// RFC 9457.
+///
+/// ```no_run
+/// use axum::extract::Path;
+/// use problem_details::axum::{ProblemFormat, ProblemResponse};
+/// use problem_details::ProblemDetails;
+///
+/// async fn handler(format: ProblemFormat) -> ProblemResponse<()> {
+///     ProblemResponse::new(ProblemDetails::new(), format)
+/// }
+/// ```
+#[cfg(all(feature = "json", feature = "xml"))]
+pub struct ProblemResponse<Ext> {
+    details: ProblemDetails<Ext>,
+    format: ProblemFormat,
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+impl<Ext> ProblemResponse<Ext> {
+    /// Creates a new [`ProblemResponse`] from a [`ProblemDetails`] and a
+    /// previously negotiated [`ProblemFormat`].
+    pub fn new(details: ProblemDetails<Ext>, format: ProblemFormat) -> Self {
+        Self { details, format }
+    }
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+impl<Ext: serde::Serialize> IntoResponse for ProblemResponse<Ext> {
+    fn into_response(self) -> Response {
+        let status = self
+            .details
+            .status
+            .unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+        let content_type = self.format.content_type();
+
+        let body = match self.format {
+            ProblemFormat::Json => serde_json::to_vec(&self.details).unwrap_or_default(),
+            ProblemFormat::Xml => quick_xml::se::to_string(&self.details)
+                .unwrap_or_default()
+                .into_bytes(),
+        };
+
+        (status, [(header::CONTENT_TYPE, content_type)], body).into_response()
+    }
+}
@@ -0,0 +1,125 @@
+//! Extension traits that make it easy to turn arbitrary [`Result`]/[`Option`]
+//! values into `Result<T, `[`ProblemDetails`]`>`, capturing the call site so
+//! services can log precisely where a problem was raised.
+//!
+//! The call site is captured via [`#[track_caller]`](std::panic::Location::caller)
+//! and stored on the resulting [`ProblemDetails`], but it is never part of the
+//! serialized wire format. The [`ProblemDetails::location`] accessor exists
+//! only when the `debug` feature is enabled; the derived `Debug` output of
+//! [`ProblemDetails`] includes the location in every configuration. Enabling
+//! the `backtrace` feature additionally captures a
+//! [`Backtrace`](std::backtrace::Backtrace) at the same call site with
+//! [`Backtrace::capture`](std::backtrace::Backtrace::capture), readable
+//! through [`ProblemDetails::backtrace`]; its frames are only recorded when
+//! `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` enables backtraces.
+//!
+//! ```
+//! # #[cfg(feature = "debug")] {
+//! use problem_details::ext::{OptionExt, ResultExt};
+//! use http::StatusCode;
+//!
+//! let (line, error) = (line!(), None::<()>.ok_or_problem(StatusCode::NOT_FOUND).unwrap_err());
+//! assert_eq!(error.location().unwrap().line(), line);
+//!
+//! let (line, error) = (line!(), Err::<(), _>("boom").catch_err(StatusCode::BAD_REQUEST, "Invalid input").unwrap_err());
+//! assert_eq!(error.location().unwrap().line(), line);
+//! assert_eq!(error.detail.as_deref(), Some("boom"));
+//! # }
+//! ```
+
+use http::{StatusCode, Uri};
+use std::fmt::Display;
+use std::panic::Location;
+
+use crate::ProblemDetails;
+
+/// Stamps `details` with a call site already captured by a `#[track_caller]`
+/// caller. `Location::caller()` must not be called from here: this function
+/// isn't `#[track_caller]` itself, so it would only ever report its own
+/// location instead of the real call site in user code.
+fn with_location<Ext>(
+    mut details: ProblemDetails<Ext>,
+    location: &'static Location<'static>,
+) -> ProblemDetails<Ext> {
+    details.location = Some(location);
+    #[cfg(feature = "backtrace")]
+    {
+        details.backtrace = Some(std::backtrace::Backtrace::capture());
+    }
+    details
+}
+
+/// Converts a [`Result`] into `Result<T, `[`ProblemDetails`]`>`.
+pub trait ResultExt<T, E> {
+    /// Maps the error variant into a [`ProblemDetails`] with the given
+    /// `status` and `title`, using the error's [`Display`] output as `detail`.
+    // `ProblemDetails` carries a `location` (and, with the `backtrace`
+    // feature, a `Backtrace`) alongside the RFC 9457 members, which pushes it
+    // past clippy's large-error threshold. Boxing it would add an
+    // allocation and an extra indirection to every caller just to satisfy
+    // the lint, which isn't worth it for a type whose whole point is to be
+    // returned as an error.
+    #[allow(clippy::result_large_err)]
+    #[track_caller]
+    fn catch_err(self, status: StatusCode, title: impl Into<String>) -> Result<T, ProblemDetails>;
+}
+
+impl<T, E: Display> ResultExt<T, E> for Result<T, E> {
+    #[allow(clippy::result_large_err)]
+    #[track_caller]
+    fn catch_err(self, status: StatusCode, title: impl Into<String>) -> Result<T, ProblemDetails> {
+        let location = Location::caller();
+        self.map_err(|error| {
+            with_location(
+                ProblemDetails::new()
+                    .with_status(status)
+                    .with_title(title)
+                    .with_detail(error.to_string()),
+                location,
+            )
+        })
+    }
+}
+
+/// Converts an [`Option`] into `Result<T, `[`ProblemDetails`]`>`.
+pub trait OptionExt<T> {
+    /// Turns [`None`] into a [`ProblemDetails::from_status_code`] for the
+    /// given `status`.
+    #[allow(clippy::result_large_err)]
+    #[track_caller]
+    fn ok_or_problem(self, status: StatusCode) -> Result<T, ProblemDetails>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[allow(clippy::result_large_err)]
+    #[track_caller]
+    fn ok_or_problem(self, status: StatusCode) -> Result<T, ProblemDetails> {
+        let location = Location::caller();
+        self.ok_or_else(|| with_location(ProblemDetails::from_status_code(status), location))
+    }
+}
+
+/// Fluent combinators for a `Result` that has already been converted into a
+/// [`ProblemDetails`] error, so call sites can keep enriching it without
+/// unwrapping and rebuilding the whole value.
+pub trait ProblemResultExt<T, Ext> {
+    /// Sets the `detail` member on the error, if any.
+    #[allow(clippy::result_large_err)]
+    fn with_problem_detail(self, detail: impl Into<String>) -> Result<T, ProblemDetails<Ext>>;
+
+    /// Sets the `instance` member on the error, if any.
+    #[allow(clippy::result_large_err)]
+    fn with_problem_instance(self, instance: Uri) -> Result<T, ProblemDetails<Ext>>;
+}
+
+impl<T, Ext> ProblemResultExt<T, Ext> for Result<T, ProblemDetails<Ext>> {
+    #[allow(clippy::result_large_err)]
+    fn with_problem_detail(self, detail: impl Into<String>) -> Result<T, ProblemDetails<Ext>> {
+        self.map_err(|error| error.with_detail(detail))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn with_problem_instance(self, instance: Uri) -> Result<T, ProblemDetails<Ext>> {
+        self.map_err(|error| error.with_instance(instance))
+    }
+}
@@ -0,0 +1,96 @@
+//! Dynamic access to extension members by key, for
+//! `ProblemDetails<HashMap<String, serde_json::Value>>` (or any other erased
+//! extension map), so middleware-style layers can enrich a problem object
+//! without rebuilding the whole struct.
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use problem_details::{Extensions, Fields, ProblemDetails};
+//!
+//! let mut problem = ProblemDetails::new().with_extensions(HashMap::new());
+//! problem.set_field("retry_after", 30u32);
+//! assert_eq!(problem.get_field::<u32>("retry_after"), Some(30));
+//! assert_eq!(problem.get_field::<u32>("missing"), None);
+//!
+//! problem.declare_namespace("https://example.com/schemas/retry");
+//! assert_eq!(problem.namespaces(), vec!["https://example.com/schemas/retry"]);
+//! ```
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::ProblemDetails;
+
+/// Key used to store the set of declared extension namespaces inside the
+/// extension map, see [`Extensions`].
+const NAMESPACES_FIELD: &str = "$schemas";
+
+/// Get and set individual extension members by key, without rebuilding the
+/// whole [`ProblemDetails`].
+pub trait Fields {
+    /// Returns the raw extension map.
+    fn fields(&self) -> &HashMap<String, Value>;
+
+    /// Returns a mutable reference to the raw extension map.
+    fn fields_mut(&mut self) -> &mut HashMap<String, Value>;
+
+    /// Deserializes the extension member stored under `key` into `T`, if present.
+    fn get_field<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.fields()
+            .get(key)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Serializes `value` and stores it as the extension member under `key`,
+    /// returning the previous value, if any, or `None` if `value` could not
+    /// be serialized to JSON (e.g. a map with non-string keys) — in which
+    /// case the field is left untouched.
+    fn set_field<T: Serialize>(&mut self, key: impl Into<String>, value: T) -> Option<Value> {
+        let value = serde_json::to_value(value).ok()?;
+        self.fields_mut().insert(key.into(), value)
+    }
+}
+
+impl Fields for ProblemDetails<HashMap<String, Value>> {
+    fn fields(&self) -> &HashMap<String, Value> {
+        &self.extensions
+    }
+
+    fn fields_mut(&mut self) -> &mut HashMap<String, Value> {
+        &mut self.extensions
+    }
+}
+
+/// Tracks which extension "namespaces" (schema URIs) are declared on a
+/// problem object, so downstream layers can introspect which extension sets
+/// are present before looking for specific fields.
+///
+/// Namespaces are stored under the `"$schemas"` key of the same extension
+/// map that gets flattened into the wire JSON — unlike
+/// [`ProblemDetails`]'s own `location`/`backtrace` fields, this is not
+/// `serde(skip)`ped. A caller that independently sets an extension field
+/// literally named `"$schemas"` will collide with it.
+pub trait Extensions: Fields {
+    /// Returns the set of declared extension namespaces.
+    fn namespaces(&self) -> Vec<String> {
+        self.get_field(NAMESPACES_FIELD).unwrap_or_default()
+    }
+
+    /// Declares an extension namespace, if it isn't already present.
+    ///
+    /// See the [`Extensions`] trait docs for the `"$schemas"` key this writes
+    /// to.
+    fn declare_namespace(&mut self, namespace: impl Into<String>) {
+        let namespace = namespace.into();
+        let mut namespaces = self.namespaces();
+        if !namespaces.contains(&namespace) {
+            namespaces.push(namespace);
+            self.set_field(NAMESPACES_FIELD, namespaces);
+        }
+    }
+}
+
+impl Extensions for ProblemDetails<HashMap<String, Value>> {}
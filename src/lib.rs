@@ -108,22 +108,47 @@
 //!              return `ProblemDetails` as responses and errors.
 //! - **actix**:  Enables integration with the [`actix-web`](https://crates.io/crates/actix-web) web framework, allowing to
 //!              return `ProblemDetails` as errors.
+//! - **debug**: Exposes [`ProblemDetails::location`] so the call site captured by the
+//!              [`ext`] traits can be read back for logging.
+//! - **backtrace**: Additionally captures a backtrace at the call site with
+//!              `Backtrace::capture`, readable through `ProblemDetails::backtrace`.
+//!              Its frames are only recorded when `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+//!              enables backtraces.
 //!
 //! # Caveats
 //!
-//! This crate is not fully compliant with the RFC, because it fails to deserialize
-//! JSON values containing properties with incorrect types (required by
+//! By default, this crate is not fully compliant with the RFC, because it fails to
+//! deserialize JSON values containing members with incorrect types, reporting only the
+//! first one as a serde error, and accepts any `status` from 100 to 999 (see
 //! [Chapter 3.1 of the RFC](https://www.rfc-editor.org/rfc/rfc9457.pdf#name-members-of-a-problem-detail)).
+//! Use [`ProblemDetails::from_json_strict`] to check every member against §3.1 and get
+//! all violations at once.
 
 #![warn(missing_docs)]
 #![forbid(unsafe_code)]
 
+mod macros;
 mod problem_details;
 mod problem_type;
 
 pub use problem_details::*;
 pub use problem_type::*;
 
+// Result/Option -> ProblemDetails conversion helpers
+pub mod ext;
+
+// Dynamic extension field access
+#[cfg(feature = "serde")]
+mod fields;
+#[cfg(feature = "serde")]
+pub use fields::*;
+
+// Strict RFC 9457 §3.1 deserialization
+#[cfg(feature = "json")]
+mod strict;
+#[cfg(feature = "json")]
+pub use strict::*;
+
 // Axum Support
 #[cfg(feature = "axum")]
 pub mod axum;
@@ -0,0 +1,98 @@
+/// Generates a dedicated problem constructor from a compact declaration,
+/// instead of chaining [`with_type`](crate::ProblemDetails::with_type)/
+/// [`with_title`](crate::ProblemDetails::with_title)/
+/// [`with_status`](crate::ProblemDetails::with_status) at every call site.
+///
+/// Given a `type` URI, default `title` and default `status`, and an optional
+/// extension struct, this emits a function returning a fully-typed
+/// [`ProblemDetails<Ext>`](crate::ProblemDetails) with those defaults applied,
+/// taking only the extension fields as parameters. Centralizing a crate's
+/// catalog of API errors this way reduces drift between endpoints.
+///
+/// # Example
+///
+/// ```rust
+/// use http::StatusCode;
+/// use problem_details::define_problem;
+///
+/// define_problem! {
+///     /// You do not have enough credit.
+///     pub fn out_of_credit(
+///         type: "https://example.com/probs/out-of-credit",
+///         title: "You do not have enough credit.",
+///         status: StatusCode::FORBIDDEN,
+///     ) -> OutOfCreditExt {
+///         pub balance: u32,
+///         pub accounts: Vec<String>,
+///     }
+/// }
+///
+/// let problem = out_of_credit(30, vec!["/account/12345".to_string()]);
+/// assert_eq!(problem.status, Some(StatusCode::FORBIDDEN));
+/// assert_eq!(problem.extensions.balance, 30);
+/// ```
+///
+/// An extension-less problem just omits the trailing block:
+///
+/// ```rust
+/// use http::StatusCode;
+/// use problem_details::define_problem;
+///
+/// define_problem! {
+///     /// The request timed out.
+///     pub fn timed_out(
+///         type: "https://example.com/probs/timed-out",
+///         title: "The request timed out.",
+///         status: StatusCode::REQUEST_TIMEOUT,
+///     )
+/// }
+///
+/// let problem = timed_out();
+/// assert_eq!(problem.status, Some(StatusCode::REQUEST_TIMEOUT));
+/// ```
+#[macro_export]
+macro_rules! define_problem {
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident(
+            type: $type:expr,
+            title: $title:expr,
+            status: $status:expr $(,)?
+        ) -> $ext:ident {
+            $($field_vis:vis $field:ident: $field_ty:ty),* $(,)?
+        }
+    ) => {
+        #[doc = concat!("Extension fields for [`", stringify!($name), "`].")]
+        #[derive(Debug, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+        $vis struct $ext {
+            $($field_vis $field: $field_ty),*
+        }
+
+        $(#[$meta])*
+        $vis fn $name($($field: $field_ty),*) -> $crate::ProblemDetails<$ext> {
+            $crate::ProblemDetails::new()
+                .with_type(::http::Uri::from_static($type))
+                .with_title($title)
+                .with_status($status)
+                .with_extensions($ext { $($field),* })
+        }
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident(
+            type: $type:expr,
+            title: $title:expr,
+            status: $status:expr $(,)?
+        )
+    ) => {
+        $(#[$meta])*
+        $vis fn $name() -> $crate::ProblemDetails<()> {
+            $crate::ProblemDetails::new()
+                .with_type(::http::Uri::from_static($type))
+                .with_title($title)
+                .with_status($status)
+        }
+    };
+}
@@ -0,0 +1,148 @@
+//! Integration with the [`poem`](https://docs.rs/poem) web framework.
+
+#[cfg(any(feature = "json", feature = "xml"))]
+use poem::http::{header, StatusCode};
+#[cfg(any(feature = "json", feature = "xml"))]
+use poem::{IntoResponse, Response};
+
+#[cfg(all(feature = "json", feature = "xml"))]
+use poem::http::{HeaderMap, HeaderValue};
+
+#[cfg(any(feature = "json", feature = "xml"))]
+use crate::ProblemDetails;
+
+#[cfg(feature = "json")]
+const PROBLEM_JSON: &str = "application/problem+json";
+#[cfg(feature = "xml")]
+const PROBLEM_XML: &str = "application/problem+xml";
+
+#[cfg(all(feature = "json", not(feature = "xml")))]
+impl<Ext: serde::Serialize + Send> IntoResponse for ProblemDetails<Ext> {
+    fn into_response(self) -> Response {
+        let status = self.status.unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = serde_json::to_vec(&self).unwrap_or_default();
+        Response::builder()
+            .status(status)
+            .header(header::CONTENT_TYPE, PROBLEM_JSON)
+            .body(body)
+    }
+}
+
+#[cfg(all(feature = "xml", not(feature = "json")))]
+impl<Ext: serde::Serialize + Send> IntoResponse for ProblemDetails<Ext> {
+    fn into_response(self) -> Response {
+        let status = self.status.unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = quick_xml::se::to_string(&self).unwrap_or_default();
+        Response::builder()
+            .status(status)
+            .header(header::CONTENT_TYPE, PROBLEM_XML)
+            .body(body)
+    }
+}
+
+/// The representation negotiated from an incoming `Accept` header, see
+/// [`ProblemResponse`].
+#[cfg(all(feature = "json", feature = "xml"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemFormat {
+    /// Serialize as `application/problem+json`.
+    Json,
+    /// Serialize as `application/problem+xml`.
+    Xml,
+}
+
+/// Returns whether `accept` accepts `media_type`, i.e. it lists it with no
+/// `q` parameter or a `q` parameter greater than zero. A `q=0` entry is an
+/// explicit refusal of that media type and must not be treated as accepted.
+#[cfg(all(feature = "json", feature = "xml"))]
+fn accepts(accept: &str, media_type: &str) -> bool {
+    accept.split(',').any(|entry| {
+        let mut params = entry.split(';').map(str::trim);
+        if params.next() != Some(media_type) {
+            return false;
+        }
+        let q = params
+            .find_map(|param| param.strip_prefix("q="))
+            .and_then(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        q > 0.0
+    })
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+impl ProblemFormat {
+    /// Negotiates a format from an `Accept` header, preferring XML only when
+    /// it is requested (with a non-zero `q`) and JSON is not, and falling
+    /// back to JSON otherwise.
+    pub fn negotiate(headers: &HeaderMap) -> Self {
+        let accept = headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        if accepts(accept, PROBLEM_XML) && !accepts(accept, PROBLEM_JSON) {
+            Self::Xml
+        } else {
+            Self::Json
+        }
+    }
+
+    fn content_type(self) -> HeaderValue {
+        HeaderValue::from_static(match self {
+            Self::Json => PROBLEM_JSON,
+            Self::Xml => PROBLEM_XML,
+        })
+    }
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+impl<'a> poem::FromRequest<'a> for ProblemFormat {
+    async fn from_request(
+        req: &'a poem::Request,
+        _body: &mut poem::RequestBody,
+    ) -> poem::Result<Self> {
+        Ok(Self::negotiate(req.headers()))
+    }
+}
+
+/// Wraps a [`ProblemDetails`] together with a negotiated [`ProblemFormat`],
+/// so a single handler can satisfy clients requesting either
+/// `application/problem+json` or `application/problem+xml`, as intended by
+/// RFC 9457.
+#[cfg(all(feature = "json", feature = "xml"))]
+pub struct ProblemResponse<Ext> {
+    details: ProblemDetails<Ext>,
+    format: ProblemFormat,
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+impl<Ext> ProblemResponse<Ext> {
+    /// Creates a new [`ProblemResponse`] from a [`ProblemDetails`] and a
+    /// previously negotiated [`ProblemFormat`].
+    pub fn new(details: ProblemDetails<Ext>, format: ProblemFormat) -> Self {
+        Self { details, format }
+    }
+}
+
+#[cfg(all(feature = "json", feature = "xml"))]
+impl<Ext: serde::Serialize + Send> IntoResponse for ProblemResponse<Ext> {
+    fn into_response(self) -> Response {
+        let status = self
+            .details
+            .status
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let content_type = self.format.content_type();
+
+        let body = match self.format {
+            ProblemFormat::Json => serde_json::to_vec(&self.details).unwrap_or_default(),
+            ProblemFormat::Xml => quick_xml::se::to_string(&self.details)
+                .unwrap_or_default()
+                .into_bytes(),
+        };
+
+        Response::builder()
+            .status(status)
+            .header(header::CONTENT_TYPE, content_type)
+            .body(body)
+    }
+}
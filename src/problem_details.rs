@@ -0,0 +1,191 @@
+use http::{StatusCode, Uri};
+
+/// A problem details object as defined in
+/// [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457).
+///
+/// See the [crate-level documentation](crate) for usage examples.
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProblemDetails<Ext = ()> {
+    /// A URI reference that identifies the problem type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "type",
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "crate::serde::uri::option"
+        )
+    )]
+    pub r#type: Option<Uri>,
+
+    /// The HTTP status code for this occurrence of the problem.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "crate::serde::status_code::option"
+        )
+    )]
+    pub status: Option<StatusCode>,
+
+    /// A short, human-readable summary of the problem type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub title: Option<String>,
+
+    /// A human-readable explanation specific to this occurrence of the problem.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub detail: Option<String>,
+
+    /// A URI reference that identifies the specific occurrence of the problem.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "crate::serde::uri::option"
+        )
+    )]
+    pub instance: Option<Uri>,
+
+    /// Additional, type-safe extension members.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub extensions: Ext,
+
+    /// The source location the problem was raised at, captured by
+    /// [`ResultExt`](crate::ext::ResultExt)/[`OptionExt`](crate::ext::OptionExt).
+    ///
+    /// Never serialized, so services can log precisely where a problem was
+    /// raised without the location leaking into the wire format. The
+    /// [`location`](Self::location) accessor exists only when the `debug`
+    /// feature is enabled; the derived `Debug` output includes this field in
+    /// every configuration.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) location: Option<&'static std::panic::Location<'static>>,
+
+    /// The backtrace captured at the point the problem was raised, when the
+    /// `backtrace` feature is enabled.
+    #[cfg(feature = "backtrace")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) backtrace: Option<std::backtrace::Backtrace>,
+}
+
+// `std::backtrace::Backtrace` implements neither `Clone`, `PartialEq` nor
+// `Eq`, so these impls are written by hand instead of derived. Equality and
+// cloning only ever consider the RFC 9457 members, the extensions and the
+// captured location; the backtrace is debug-only metadata.
+impl<Ext: Clone> Clone for ProblemDetails<Ext> {
+    fn clone(&self) -> Self {
+        Self {
+            r#type: self.r#type.clone(),
+            status: self.status,
+            title: self.title.clone(),
+            detail: self.detail.clone(),
+            instance: self.instance.clone(),
+            extensions: self.extensions.clone(),
+            location: self.location,
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
+        }
+    }
+}
+
+impl<Ext: PartialEq> PartialEq for ProblemDetails<Ext> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r#type == other.r#type
+            && self.status == other.status
+            && self.title == other.title
+            && self.detail == other.detail
+            && self.instance == other.instance
+            && self.extensions == other.extensions
+            && self.location == other.location
+    }
+}
+
+impl<Ext: Eq> Eq for ProblemDetails<Ext> {}
+
+impl ProblemDetails<()> {
+    /// Creates a new, empty [`ProblemDetails`] without any extension members.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`ProblemDetails`] pre-filled from an HTTP status code,
+    /// using the status code's canonical reason phrase as the title.
+    pub fn from_status_code(status: StatusCode) -> Self {
+        Self {
+            status: Some(status),
+            title: status.canonical_reason().map(str::to_string),
+            ..Self::default()
+        }
+    }
+}
+
+impl<Ext> ProblemDetails<Ext> {
+    /// Sets the `type` member.
+    pub fn with_type(mut self, r#type: Uri) -> Self {
+        self.r#type = Some(r#type);
+        self
+    }
+
+    /// Sets the `status` member.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `title` member.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the `detail` member.
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Sets the `instance` member.
+    pub fn with_instance(mut self, instance: Uri) -> Self {
+        self.instance = Some(instance);
+        self
+    }
+
+    /// Replaces the extension members, changing the extension type.
+    pub fn with_extensions<NewExt>(self, extensions: NewExt) -> ProblemDetails<NewExt> {
+        ProblemDetails {
+            r#type: self.r#type,
+            status: self.status,
+            title: self.title,
+            detail: self.detail,
+            instance: self.instance,
+            extensions,
+            location: self.location,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+        }
+    }
+
+    /// The source location the problem was raised at, if it was constructed
+    /// through [`ResultExt`](crate::ext::ResultExt) or
+    /// [`OptionExt`](crate::ext::OptionExt) and the `debug` feature is enabled.
+    #[cfg(feature = "debug")]
+    pub fn location(&self) -> Option<&'static std::panic::Location<'static>> {
+        self.location
+    }
+
+    /// The backtrace captured when the problem was raised, if the
+    /// `backtrace` feature is enabled.
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.backtrace.as_ref()
+    }
+}
@@ -0,0 +1,132 @@
+use http::{StatusCode, Uri};
+
+use crate::ProblemDetails;
+
+/// A well-known kind of problem that can be turned into a [`ProblemDetails`].
+///
+/// Implementing this trait for an enum or const lets callers build consistent
+/// `ProblemDetails` values from a single catalog entry instead of repeating
+/// the same `type`/`title`/`status` combination at every call site.
+pub trait ProblemType {
+    /// The URI reference that identifies this problem type.
+    fn type_uri(&self) -> Uri;
+
+    /// The default, human-readable title for this problem type.
+    fn title(&self) -> &str;
+
+    /// The default HTTP status code for this problem type.
+    fn status(&self) -> StatusCode;
+
+    /// A stable, machine-readable integer code for this problem type, so
+    /// clients can match on it without parsing the `type` URI.
+    fn code(&self) -> u32;
+}
+
+/// A catalog of common API problem types, each pairing a canonical `type`
+/// URI, default `title` and `status` with a stable integer [`code`](ProblemType::code).
+///
+/// Using a shared catalog instead of hand-writing URIs at every call site
+/// keeps the `type`/`title`/`status`/`code` combination consistent across
+/// endpoints. See [`ProblemDetails::from_problem_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CommonProblemType {
+    /// The request body or parameters failed validation.
+    ValidationFailed,
+    /// The request lacks valid authentication credentials.
+    Unauthorized,
+    /// The authenticated caller isn't allowed to perform this action.
+    Forbidden,
+    /// The requested resource doesn't exist.
+    NotFound,
+    /// The request conflicts with the current state of the resource.
+    Conflict,
+    /// The caller has exceeded the allowed request rate.
+    RateLimited,
+    /// An unexpected error occurred while processing the request.
+    InternalError,
+}
+
+impl ProblemType for CommonProblemType {
+    fn type_uri(&self) -> Uri {
+        Uri::from_static(match self {
+            Self::ValidationFailed => "https://problems.nkz-soft.dev/validation-failed",
+            Self::Unauthorized => "https://problems.nkz-soft.dev/unauthorized",
+            Self::Forbidden => "https://problems.nkz-soft.dev/forbidden",
+            Self::NotFound => "https://problems.nkz-soft.dev/not-found",
+            Self::Conflict => "https://problems.nkz-soft.dev/conflict",
+            Self::RateLimited => "https://problems.nkz-soft.dev/rate-limited",
+            Self::InternalError => "https://problems.nkz-soft.dev/internal-error",
+        })
+    }
+
+    fn title(&self) -> &str {
+        match self {
+            Self::ValidationFailed => "One or more validation errors occurred.",
+            Self::Unauthorized => "Authentication is required.",
+            Self::Forbidden => "You do not have permission to perform this action.",
+            Self::NotFound => "The requested resource could not be found.",
+            Self::Conflict => "The request conflicts with the current state of the resource.",
+            Self::RateLimited => "Too many requests.",
+            Self::InternalError => "An unexpected error occurred.",
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::ValidationFailed => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden => StatusCode::FORBIDDEN,
+            Self::NotFound => StatusCode::NOT_FOUND,
+            Self::Conflict => StatusCode::CONFLICT,
+            Self::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            Self::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::ValidationFailed => 1_000,
+            Self::Unauthorized => 1_001,
+            Self::Forbidden => 1_002,
+            Self::NotFound => 1_003,
+            Self::Conflict => 1_004,
+            Self::RateLimited => 1_005,
+            Self::InternalError => 1_006,
+        }
+    }
+}
+
+/// Extension member carrying the numeric [`code`](ProblemType::code) of a
+/// [`ProblemType`], flattened into the problem details object by
+/// [`ProblemDetails::from_problem_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProblemTypeCode {
+    /// The stable, machine-readable problem code.
+    pub code: u32,
+}
+
+impl ProblemDetails<()> {
+    /// Creates a [`ProblemDetails`] pre-filled from a [`ProblemType`],
+    /// setting `type`, `title` and `status` from the catalog entry and
+    /// emitting its numeric `code` as a flattened extension member.
+    ///
+    /// ```
+    /// use http::StatusCode;
+    /// use problem_details::{CommonProblemType, ProblemDetails, ProblemType};
+    ///
+    /// let problem = ProblemDetails::from_problem_type(CommonProblemType::Conflict);
+    /// assert_eq!(problem.status, Some(StatusCode::CONFLICT));
+    /// assert_eq!(problem.extensions.code, CommonProblemType::Conflict.code());
+    /// ```
+    pub fn from_problem_type(problem_type: impl ProblemType) -> ProblemDetails<ProblemTypeCode> {
+        ProblemDetails::new()
+            .with_type(problem_type.type_uri())
+            .with_title(problem_type.title())
+            .with_status(problem_type.status())
+            .with_extensions(ProblemTypeCode {
+                code: problem_type.code(),
+            })
+    }
+}
@@ -0,0 +1,63 @@
+//! Serde (de)serialization helpers for types that don't implement
+//! [`Serialize`](serde::Serialize)/[`Deserialize`](serde::Deserialize) the way
+//! we need them to for the problem details wire format.
+
+pub(crate) mod uri {
+    pub(crate) mod option {
+        use http::Uri;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub(crate) fn serialize<S>(uri: &Option<Uri>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match uri {
+                Some(uri) => serializer.serialize_str(&uri.to_string()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Uri>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let uri = Option::<String>::deserialize(deserializer)?;
+            uri.map(|uri| uri.parse::<Uri>().map_err(serde::de::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+pub(crate) mod status_code {
+    pub(crate) mod option {
+        use http::StatusCode;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub(crate) fn serialize<S>(
+            status: &Option<StatusCode>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match status {
+                Some(status) => serializer.serialize_u16(status.as_u16()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub(crate) fn deserialize<'de, D>(
+            deserializer: D,
+        ) -> Result<Option<StatusCode>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let status = Option::<u16>::deserialize(deserializer)?;
+            status
+                .map(|status| {
+                    StatusCode::from_u16(status).map_err(serde::de::Error::custom)
+                })
+                .transpose()
+        }
+    }
+}
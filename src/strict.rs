@@ -0,0 +1,193 @@
+//! A strict RFC 9457 [§3.1](https://www.rfc-editor.org/rfc/rfc9457.pdf#name-members-of-a-problem-detail)
+//! deserialization mode.
+//!
+//! The regular [`serde::Deserialize`] impl for [`ProblemDetails`] is lenient:
+//! it stops at the first member with an incorrect type and reports it as a
+//! single serde error, and it accepts any `status` from 100 to 999, as
+//! documented in the [crate-level caveats](crate#caveats). This module adds
+//! an opt-in strict entry point that enforces §3.1 instead, returning a
+//! [`StrictDeserializeError`] enumerating every violating member. The
+//! lenient behavior remains the default to avoid breaking existing users.
+//!
+//! ```
+//! use problem_details::ProblemDetails;
+//!
+//! // A well-formed object still deserializes.
+//! let ok = ProblemDetails::<()>::from_json_strict::<()>(r#"{"status": 404, "title": "Not found"}"#);
+//! assert!(ok.is_ok());
+//!
+//! // `type` must be a valid URI string.
+//! let err = ProblemDetails::<()>::from_json_strict::<()>(r#"{"type": "not a uri"}"#).unwrap_err();
+//! assert_eq!(err.violations[0].member, "type");
+//!
+//! // `instance` must be a valid URI string.
+//! let err = ProblemDetails::<()>::from_json_strict::<()>(r#"{"instance": "not a uri"}"#).unwrap_err();
+//! assert_eq!(err.violations[0].member, "instance");
+//!
+//! // `status` must be an integer...
+//! let err = ProblemDetails::<()>::from_json_strict::<()>(r#"{"status": "404"}"#).unwrap_err();
+//! assert_eq!(err.violations[0].member, "status");
+//!
+//! // ...in 100..=599.
+//! let err = ProblemDetails::<()>::from_json_strict::<()>(r#"{"status": 999}"#).unwrap_err();
+//! assert_eq!(err.violations[0].member, "status");
+//!
+//! // `title` must be a string.
+//! let err = ProblemDetails::<()>::from_json_strict::<()>(r#"{"title": 42}"#).unwrap_err();
+//! assert_eq!(err.violations[0].member, "title");
+//!
+//! // `detail` must be a string.
+//! let err = ProblemDetails::<()>::from_json_strict::<()>(r#"{"detail": 42}"#).unwrap_err();
+//! assert_eq!(err.violations[0].member, "detail");
+//! ```
+
+use std::fmt;
+
+use http::Uri;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::ProblemDetails;
+
+/// A single problem details member that doesn't satisfy RFC 9457 §3.1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberViolation {
+    /// The name of the violating member.
+    pub member: &'static str,
+    /// A human-readable description of why the member is invalid.
+    pub reason: String,
+}
+
+/// The error returned by [`ProblemDetails::from_json_strict`] when one or
+/// more members don't satisfy RFC 9457 §3.1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrictDeserializeError {
+    /// Every member that violates §3.1, in the order they were checked.
+    pub violations: Vec<MemberViolation>,
+}
+
+impl fmt::Display for StrictDeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "problem details object violates RFC 9457 \u{a7}3.1: ")?;
+        for (index, violation) in self.violations.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "`{}`: {}", violation.member, violation.reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for StrictDeserializeError {}
+
+fn check_uri_member(object: &serde_json::Map<String, Value>, member: &'static str) -> Option<MemberViolation> {
+    match object.get(member) {
+        None | Some(Value::Null) => None,
+        Some(Value::String(value)) => {
+            if value.parse::<Uri>().is_err() {
+                Some(MemberViolation {
+                    member,
+                    reason: format!("`{value}` is not a valid URI reference"),
+                })
+            } else {
+                None
+            }
+        }
+        Some(other) => Some(MemberViolation {
+            member,
+            reason: format!("expected a URI string, found {}", value_kind(other)),
+        }),
+    }
+}
+
+fn check_string_member(object: &serde_json::Map<String, Value>, member: &'static str) -> Option<MemberViolation> {
+    match object.get(member) {
+        None | Some(Value::Null) => None,
+        Some(Value::String(_)) => None,
+        Some(other) => Some(MemberViolation {
+            member,
+            reason: format!("expected a string, found {}", value_kind(other)),
+        }),
+    }
+}
+
+fn check_status_member(object: &serde_json::Map<String, Value>) -> Option<MemberViolation> {
+    match object.get("status") {
+        None | Some(Value::Null) => None,
+        Some(Value::Number(number)) => match number.as_u64() {
+            Some(status) if (100..=599).contains(&status) => None,
+            _ => Some(MemberViolation {
+                member: "status",
+                reason: format!("`{number}` is not an integer in 100..=599"),
+            }),
+        },
+        Some(other) => Some(MemberViolation {
+            member: "status",
+            reason: format!("expected an integer, found {}", value_kind(other)),
+        }),
+    }
+}
+
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+impl ProblemDetails<()> {
+    /// Deserializes `json` into a [`ProblemDetails<Ext>`], enforcing RFC 9457
+    /// [§3.1](https://www.rfc-editor.org/rfc/rfc9457.pdf#name-members-of-a-problem-detail):
+    /// `type`/`instance` must be valid URI strings, `status` must be an
+    /// integer in `100..=599`, and `title`/`detail` must be strings.
+    ///
+    /// Returns a [`StrictDeserializeError`] enumerating every violating
+    /// member, unlike the regular, lenient `Deserialize` impl, which reports
+    /// only the first wrong-typed member and accepts any `status` up to 999.
+    pub fn from_json_strict<Ext: DeserializeOwned>(
+        json: &str,
+    ) -> Result<ProblemDetails<Ext>, StrictDeserializeError> {
+        let value: Value = serde_json::from_str(json).map_err(|err| StrictDeserializeError {
+            violations: vec![MemberViolation {
+                member: "$",
+                reason: format!("input is not valid JSON: {err}"),
+            }],
+        })?;
+
+        let Some(object) = value.as_object() else {
+            return Err(StrictDeserializeError {
+                violations: vec![MemberViolation {
+                    member: "$",
+                    reason: format!("expected a JSON object, found {}", value_kind(&value)),
+                }],
+            });
+        };
+
+        let violations: Vec<MemberViolation> = [
+            check_uri_member(object, "type"),
+            check_status_member(object),
+            check_string_member(object, "title"),
+            check_string_member(object, "detail"),
+            check_uri_member(object, "instance"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if !violations.is_empty() {
+            return Err(StrictDeserializeError { violations });
+        }
+
+        serde_json::from_value(value).map_err(|err| StrictDeserializeError {
+            violations: vec![MemberViolation {
+                member: "$",
+                reason: err.to_string(),
+            }],
+        })
+    }
+}